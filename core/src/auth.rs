@@ -0,0 +1,171 @@
+//! Request authentication: API keys, mutual TLS, or both.
+
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+use anyhow::Context;
+use subtle::{Choice, ConstantTimeEq};
+
+/// How a caller is allowed to authenticate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMode {
+    /// Authenticate with a shared API key only.
+    ApiKey,
+    /// Authenticate with a client certificate verified by the TLS layer
+    /// (see `TLS_CLIENT_CA_FILE` / [`AuthConfig::client_ca_file`]).
+    MutualTls,
+    /// Accept either an API key or a client certificate.
+    Both,
+}
+
+/// Auth configuration, populated from environment variables.
+#[derive(Debug, Clone)]
+pub struct AuthConfig {
+    /// Keys parsed from the comma-separated `HTTP_API_KEY` env var.
+    pub api_keys: HashSet<String>,
+    /// Path to a file of newline-separated keys, re-read on `SIGHUP` so
+    /// operators can rotate credentials without a restart.
+    pub api_key_file: Option<String>,
+    /// PEM bundle of trusted client CAs, required when `auth_mode` is
+    /// `MutualTls` or `Both`.
+    pub client_ca_file: Option<String>,
+    pub auth_mode: AuthMode,
+    pub enabled: bool,
+}
+
+/// The authenticated caller's identity, as established by the TLS layer
+/// when mutual TLS is in use.
+///
+/// Inserted into the request's connection data by
+/// `crate::server::client_identity_conn_data` so handlers can extract it
+/// with `req.conn_data::<ClientIdentity>()` to make per-client
+/// authorization decisions.
+#[derive(Debug, Clone, Default)]
+pub struct ClientIdentity {
+    /// The client certificate's subject common name, if one was
+    /// presented and parsed successfully.
+    pub common_name: Option<String>,
+}
+
+/// The live set of valid API keys, resolved from [`AuthConfig::api_keys`]
+/// and [`AuthConfig::api_key_file`].
+///
+/// The file-backed half is re-readable via [`ApiKeyStore::reload`] so
+/// operators can rotate credentials (e.g. on `SIGHUP`) without a process
+/// restart; the env-var half is fixed for the process lifetime.
+/// Membership checks compare the candidate against every key in constant
+/// time to avoid leaking key material through response-timing
+/// side-channels; a key whose length differs from the candidate's is
+/// rejected on the length check alone, so key lengths are not hidden.
+#[derive(Clone)]
+pub struct ApiKeyStore {
+    static_keys: Arc<HashSet<String>>,
+    file_path: Option<Arc<String>>,
+    file_keys: Arc<RwLock<HashSet<String>>>,
+}
+
+impl ApiKeyStore {
+    /// Loads `config.api_keys` and, if set, the initial contents of
+    /// `config.api_key_file`.
+    pub fn load(config: &AuthConfig) -> anyhow::Result<Self> {
+        let file_keys = match &config.api_key_file {
+            Some(path) => Self::read_key_file(path)?,
+            None => HashSet::new(),
+        };
+
+        Ok(Self {
+            static_keys: Arc::new(config.api_keys.clone()),
+            file_path: config.api_key_file.clone().map(Arc::new),
+            file_keys: Arc::new(RwLock::new(file_keys)),
+        })
+    }
+
+    fn read_key_file(path: &str) -> anyhow::Result<HashSet<String>> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("reading HTTP_API_KEY_FILE {path}"))?;
+        Ok(contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    /// Re-reads `api_key_file` from disk, replacing the in-memory set.
+    /// A no-op when no file was configured.
+    pub fn reload(&self) -> anyhow::Result<()> {
+        let Some(path) = &self.file_path else {
+            return Ok(());
+        };
+        let fresh = Self::read_key_file(path)?;
+        *self.file_keys.write().expect("api key set poisoned") = fresh;
+        Ok(())
+    }
+
+    /// Reports whether `candidate` matches any configured key, scanning
+    /// every key and accumulating the result with a constant-time OR so
+    /// neither which key matched nor how many were scanned is observable
+    /// from response latency (a plain `any()`/`||` would short-circuit
+    /// on the first match and leak exactly that).
+    pub fn contains(&self, candidate: &str) -> bool {
+        let candidate = candidate.as_bytes();
+        let fold = |acc: Choice, keys: &HashSet<String>| {
+            keys.iter()
+                .fold(acc, |acc, key| acc | key.as_bytes().ct_eq(candidate))
+        };
+
+        let found = fold(Choice::from(0), &self.static_keys);
+        let found = fold(found, &self.file_keys.read().expect("api key set poisoned"));
+        found.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(api_keys: &[&str], api_key_file: Option<String>) -> AuthConfig {
+        AuthConfig {
+            api_keys: api_keys.iter().map(|key| key.to_string()).collect(),
+            api_key_file,
+            client_ca_file: None,
+            auth_mode: AuthMode::ApiKey,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn matches_a_static_key() {
+        let store = ApiKeyStore::load(&config(&["secret-1", "secret-2"], None)).unwrap();
+        assert!(store.contains("secret-1"));
+    }
+
+    #[test]
+    fn rejects_an_unknown_key() {
+        let store = ApiKeyStore::load(&config(&["secret-1"], None)).unwrap();
+        assert!(!store.contains("not-a-key"));
+    }
+
+    #[test]
+    fn matches_a_file_backed_key() {
+        static NEXT_ID: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+        let id = NEXT_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!(
+            "mcp-server-as-http-node-api-key-store-test-{}-{id}",
+            std::process::id()
+        ));
+        std::fs::write(&path, "secret-from-file\n").unwrap();
+
+        let store = ApiKeyStore::load(&config(&[], Some(path.to_string_lossy().into_owned()))).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(store.contains("secret-from-file"));
+    }
+
+    #[test]
+    fn empty_key_set_never_matches() {
+        let store = ApiKeyStore::load(&config(&[], None)).unwrap();
+        assert!(!store.contains(""));
+        assert!(!store.contains("anything"));
+    }
+}
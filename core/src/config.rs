@@ -0,0 +1,73 @@
+//! Server-wide configuration types.
+//!
+//! Values here are populated by `main.rs` from environment variables and
+//! handed to [`crate::server::McpHttpServer::new`] as a single bundle.
+
+use crate::auth::AuthConfig;
+use crate::runtime::Runtime;
+
+/// Node.js-specific runtime knobs.
+#[derive(Debug, Clone)]
+pub struct NodeConfig {
+    pub version: String,
+    pub package_manager: String,
+    pub enable_typescript: bool,
+    pub auto_install_dependencies: bool,
+}
+
+/// Per-backend runtime configuration, keyed by [`Runtime`] variant.
+#[derive(Debug, Clone)]
+pub enum RuntimeConfig {
+    Node(NodeConfig),
+}
+
+/// TLS termination settings for the HTTP(S) listener.
+///
+/// `enable_tls` is the effective toggle: it is derived from `DISABLE_TLS`
+/// in `main.rs` the same way `AuthConfig::enabled` is derived from
+/// `DISABLE_AUTH`. When `true` but `cert_file` and `key_file` aren't both
+/// set, [`crate::server::McpHttpServer::start`] falls back to plain HTTP
+/// rather than failing fast, so deployments that don't configure the TLS
+/// env vars keep working unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct TlsConfig {
+    pub cert_file: Option<String>,
+    pub key_file: Option<String>,
+    pub enable_tls: bool,
+}
+
+/// Which MCP wire transport the server exposes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    /// Plain HTTP. Registers no MCP JSON-RPC route: only `/` (a
+    /// plain-text banner) and `/healthz` are served.
+    Http,
+    /// Bidirectional JSON-RPC over a WebSocket connection.
+    ///
+    /// Not implemented yet — no route is registered for it, and
+    /// [`crate::server::McpHttpServer::start`] rejects it at startup
+    /// rather than silently behaving like `Http`.
+    WebSocket,
+    /// HTTP+SSE: `POST /messages` for client→server, `GET /sse` for the
+    /// server→client event stream.
+    Sse,
+}
+
+/// Top-level server configuration, assembled once at startup.
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub config_file: String,
+    pub server_name: String,
+    pub runtime_type: Runtime,
+    pub runtime_config: RuntimeConfig,
+    pub port: u16,
+    pub host: String,
+    pub auth: AuthConfig,
+    pub tls: TlsConfig,
+    pub transport: Transport,
+    pub enable_compression: bool,
+    pub work_directory: String,
+    /// Directory scanned for dynamically loaded runtime plugins, from
+    /// `PLUGIN_DIR`. Required when `runtime_type` is `Runtime::Plugin`.
+    pub plugin_dir: Option<String>,
+}
@@ -0,0 +1,9 @@
+//! Core library backing the `mcp-server-as-http-node` binary: server
+//! configuration, authentication, runtime selection, and the actix-web
+//! server that ties them together.
+
+pub mod auth;
+pub mod config;
+pub mod runtime;
+pub mod runtime_plugin;
+pub mod server;
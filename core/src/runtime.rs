@@ -0,0 +1,47 @@
+//! Runtime backend selection.
+//!
+//! A [`Runtime`] tells the server which process backend to spawn for a
+//! configured MCP server entry. `Node` is the only backend compiled in;
+//! `Plugin` is resolved at startup by [`crate::runtime_plugin`] against a
+//! shared library discovered under `PLUGIN_DIR`.
+
+/// Selects which backend spawns the MCP server's child process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Runtime {
+    /// The built-in Node.js backend.
+    Node,
+    /// A backend provided by a dynamically loaded plugin, named by the
+    /// value exported from its `register_runtime` symbol.
+    Plugin(String),
+}
+
+/// The built-in Node.js [`RuntimePlugin`], used whenever [`Runtime::Node`]
+/// is selected.
+pub struct NodeRuntimePlugin;
+
+impl crate::runtime_plugin::RuntimePlugin for NodeRuntimePlugin {
+    fn name(&self) -> &str {
+        "node"
+    }
+
+    fn spawn(&self, cfg: &crate::config::RuntimeConfig) -> anyhow::Result<std::process::Child> {
+        use anyhow::Context;
+        use std::process::{Command, Stdio};
+
+        let crate::config::RuntimeConfig::Node(node_cfg) = cfg;
+
+        let mut command = Command::new("node");
+        if node_cfg.enable_typescript {
+            command.arg("--loader").arg("ts-node/esm");
+        }
+        command
+            .arg("server.js")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit());
+
+        command
+            .spawn()
+            .context("spawning Node.js MCP runtime process")
+    }
+}
@@ -0,0 +1,116 @@
+//! Dynamically loaded MCP runtime backends.
+//!
+//! A plugin is a shared library (`.so`/`.dll`/`.dylib`) exporting a
+//! `register_runtime` symbol that returns a boxed [`RuntimePlugin`].
+//! [`PluginRegistry::load_dir`] scans `PLUGIN_DIR` for such libraries at
+//! startup so third parties can ship new runtimes (Deno, Bun, Python, …)
+//! without forking this crate.
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::path::Path;
+use std::process::Child;
+
+use anyhow::{anyhow, Context, Result};
+use libloading::{Library, Symbol};
+
+use crate::config::RuntimeConfig;
+
+/// The symbol every plugin library must export.
+const REGISTER_SYMBOL: &[u8] = b"register_runtime";
+
+/// A runtime backend provided outside this crate.
+pub trait RuntimePlugin: Send + Sync {
+    /// The name used to select this plugin via `RUNTIME_PLUGIN`.
+    fn name(&self) -> &str;
+    /// Spawns the MCP server child process for this backend.
+    fn spawn(&self, cfg: &RuntimeConfig) -> Result<Child>;
+}
+
+/// Signature every plugin's `register_runtime` export must match.
+///
+/// Plugins return an opaque pointer rather than `*mut dyn RuntimePlugin`
+/// directly because trait object pointers are not FFI-safe; the pointee
+/// is a boxed `Box<dyn RuntimePlugin>`, recovered with
+/// [`Box::from_raw`] after the call.
+type RegisterFn = unsafe extern "C" fn() -> *mut std::ffi::c_void;
+
+fn is_plugin_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(OsStr::to_str),
+        Some("so") | Some("dll") | Some("dylib")
+    )
+}
+
+/// Loaded plugins, keyed by [`RuntimePlugin::name`].
+///
+/// Holds the owning [`Library`] handles alongside the plugins so the
+/// shared libraries outlive any boxed trait object obtained from them.
+pub struct PluginRegistry {
+    plugins: HashMap<String, Box<dyn RuntimePlugin>>,
+    _libraries: Vec<Library>,
+}
+
+impl PluginRegistry {
+    /// Scans `dir` for shared libraries and registers each one's plugin.
+    ///
+    /// A library that fails to load or does not export
+    /// `register_runtime` is skipped with its error rather than aborting
+    /// the whole scan, so one bad plugin doesn't take down startup.
+    pub fn load_dir(dir: &str) -> Result<Self> {
+        let mut plugins = HashMap::new();
+        let mut libraries = Vec::new();
+
+        for entry in std::fs::read_dir(dir)
+            .with_context(|| format!("reading PLUGIN_DIR {dir}"))?
+        {
+            let path = entry?.path();
+            if !is_plugin_file(&path) {
+                continue;
+            }
+
+            match Self::load_one(&path) {
+                Ok((library, plugin)) => {
+                    libraries.push(library);
+                    plugins.insert(plugin.name().to_string(), plugin);
+                }
+                Err(err) => {
+                    eprintln!("[WARN] skipping plugin {}: {err:#}", path.display());
+                }
+            }
+        }
+
+        Ok(Self {
+            plugins,
+            _libraries: libraries,
+        })
+    }
+
+    /// Loads and registers a single plugin library, without touching
+    /// `self` — kept separate from `load_dir`'s loop so a failure here
+    /// can be caught and logged per-entry instead of aborting the scan.
+    fn load_one(path: &Path) -> Result<(Library, Box<dyn RuntimePlugin>)> {
+        // Safety: we only call the well-known `register_runtime` export,
+        // which plugin authors are required to implement per this
+        // module's contract.
+        unsafe {
+            let library = Library::new(path)
+                .with_context(|| format!("loading plugin {}", path.display()))?;
+            let register: Symbol<RegisterFn> = library
+                .get(REGISTER_SYMBOL)
+                .with_context(|| format!("{} has no register_runtime export", path.display()))?;
+            let raw = register();
+            if raw.is_null() {
+                return Err(anyhow!("{} register_runtime returned null", path.display()));
+            }
+            let boxed: Box<Box<dyn RuntimePlugin>> =
+                Box::from_raw(raw as *mut Box<dyn RuntimePlugin>);
+            Ok((library, *boxed))
+        }
+    }
+
+    /// Looks up a previously loaded plugin by name.
+    pub fn get(&self, name: &str) -> Option<&dyn RuntimePlugin> {
+        self.plugins.get(name).map(|plugin| plugin.as_ref())
+    }
+}
@@ -0,0 +1,103 @@
+//! `GET /healthz`: reports whether the spawned runtime child process is
+//! still alive, for orchestrators (e.g. Kubernetes) to gate traffic on
+//! actual runtime readiness rather than just TCP bind success.
+
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use actix_web::{web, HttpResponse, Responder};
+use serde::Serialize;
+
+/// How long a missed heartbeat is tolerated before `/healthz` reports
+/// unready, even though the last-observed check found the process
+/// alive.
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Tracks a spawned runtime child process's liveness on a background
+/// thread, since `/healthz` must answer without blocking on the process
+/// itself.
+#[derive(Clone)]
+pub struct RuntimeHealth {
+    pid: u32,
+    alive: Arc<AtomicBool>,
+    last_heartbeat_secs: Arc<AtomicU64>,
+}
+
+impl RuntimeHealth {
+    /// Takes ownership of `child` and polls it on a dedicated thread,
+    /// stamping a heartbeat every time it's observed still running.
+    pub fn spawn(child: Child) -> Self {
+        let pid = child.id();
+        let health = Self {
+            pid,
+            alive: Arc::new(AtomicBool::new(true)),
+            last_heartbeat_secs: Arc::new(AtomicU64::new(now_secs())),
+        };
+
+        let alive = Arc::clone(&health.alive);
+        let last_heartbeat_secs = Arc::clone(&health.last_heartbeat_secs);
+        let mut child = child;
+        std::thread::spawn(move || loop {
+            match child.try_wait() {
+                Ok(None) => {
+                    last_heartbeat_secs.store(now_secs(), Ordering::Relaxed);
+                }
+                Ok(Some(_)) | Err(_) => {
+                    alive.store(false, Ordering::Relaxed);
+                    return;
+                }
+            }
+            std::thread::sleep(Duration::from_secs(1));
+        });
+
+        health
+    }
+
+    fn is_ready(&self) -> bool {
+        self.alive.load(Ordering::Relaxed)
+            && now_secs().saturating_sub(self.last_heartbeat_secs.load(Ordering::Relaxed))
+                < HEARTBEAT_TIMEOUT.as_secs()
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// `Data` bundle backing the `/healthz` handler: the runtime liveness
+/// tracker plus the identifying fields the response surfaces.
+#[derive(Clone)]
+pub struct HealthState {
+    pub health: RuntimeHealth,
+    pub server_name: String,
+    pub runtime_type: String,
+}
+
+#[derive(Serialize)]
+struct HealthBody {
+    ready: bool,
+    server_name: String,
+    runtime_type: String,
+    pid: u32,
+}
+
+pub async fn healthz(state: web::Data<HealthState>) -> impl Responder {
+    let ready = state.health.is_ready();
+    let body = HealthBody {
+        ready,
+        server_name: state.server_name.clone(),
+        runtime_type: state.runtime_type.clone(),
+        pid: state.health.pid,
+    };
+
+    if ready {
+        HttpResponse::Ok().json(body)
+    } else {
+        HttpResponse::ServiceUnavailable().json(body)
+    }
+}
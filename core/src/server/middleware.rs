@@ -0,0 +1,73 @@
+//! The API-key / mutual-TLS authentication gate, applied to every MCP
+//! route except `/healthz`.
+
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header;
+use actix_web::middleware::Next;
+use actix_web::{web, Error, HttpResponse};
+
+use crate::auth::{ApiKeyStore, AuthConfig, AuthMode, ClientIdentity};
+
+/// Request-scoped auth state: the static configuration plus the
+/// (possibly file-backed, reloadable) key store resolved from it.
+#[derive(Clone)]
+pub struct RequestAuth {
+    pub config: AuthConfig,
+    pub keys: ApiKeyStore,
+}
+
+/// Reads a presented API key from either `Authorization: Bearer <key>`
+/// or the legacy `X-API-Key: <key>` header.
+fn presented_key(req: &ServiceRequest) -> Option<String> {
+    if let Some(value) = req.headers().get(header::AUTHORIZATION) {
+        let value = value.to_str().ok()?;
+        if let Some(token) = value.strip_prefix("Bearer ") {
+            return Some(token.trim().to_string());
+        }
+    }
+
+    req.headers()
+        .get("X-API-Key")
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .map(String::from)
+}
+
+/// `actix_web::middleware::from_fn` hook enforcing [`RequestAuth`].
+///
+/// Skipped entirely when [`AuthConfig::enabled`] is `false`. Otherwise
+/// authorizes per [`AuthConfig::auth_mode`]: an API key matching
+/// [`ApiKeyStore`], a client identity established by the TLS layer (see
+/// [`super::client_identity_conn_data`]), or either.
+pub async fn require_auth<B: MessageBody + 'static>(
+    req: ServiceRequest,
+    next: Next<B>,
+) -> Result<ServiceResponse<EitherBody<B>>, Error> {
+    let Some(auth) = req.app_data::<web::Data<RequestAuth>>().cloned() else {
+        return Ok(req.into_response(HttpResponse::InternalServerError().finish().map_into_right_body()));
+    };
+
+    if !auth.config.enabled {
+        return next.call(req).await.map(ServiceResponse::map_into_left_body);
+    }
+
+    let has_identity = req
+        .conn_data::<ClientIdentity>()
+        .is_some_and(|identity| identity.common_name.is_some());
+    let key_ok = presented_key(&req).is_some_and(|key| auth.keys.contains(&key));
+
+    let authorized = match auth.config.auth_mode {
+        AuthMode::ApiKey => key_ok,
+        AuthMode::MutualTls => has_identity,
+        AuthMode::Both => key_ok || has_identity,
+    };
+
+    if authorized {
+        next.call(req).await.map(ServiceResponse::map_into_left_body)
+    } else {
+        Ok(req
+            .into_response(HttpResponse::Unauthorized().finish())
+            .map_into_right_body())
+    }
+}
@@ -0,0 +1,328 @@
+//! The actix-web server: binding (plain HTTP or TLS), authentication,
+//! and the MCP transport routes.
+
+use std::any::Any;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::Arc;
+
+use actix_tls::accept::rustls_0_23::TlsStream;
+use actix_web::dev::Extensions;
+use actix_web::rt::net::TcpStream;
+use actix_web::{web, App, HttpResponse, HttpServer};
+use anyhow::Context;
+use rustls::pki_types::CertificateDer;
+use rustls::server::danger::ClientCertVerifier;
+use rustls::server::WebPkiClientVerifier;
+use rustls::RootCertStore;
+
+pub mod health;
+pub mod middleware;
+pub mod sse;
+
+use crate::auth::{ApiKeyStore, AuthConfig, AuthMode, ClientIdentity};
+use crate::config::{ServerConfig, TlsConfig, Transport};
+use crate::runtime::{NodeRuntimePlugin, Runtime};
+use crate::runtime_plugin::{PluginRegistry, RuntimePlugin};
+use actix_web::middleware::{from_fn, Compress, Condition};
+use health::{HealthState, RuntimeHealth};
+use middleware::{require_auth, RequestAuth};
+use sse::SseState;
+
+fn load_certs(path: &str) -> anyhow::Result<Vec<CertificateDer<'static>>> {
+    let mut reader =
+        BufReader::new(File::open(path).with_context(|| format!("opening {path}"))?);
+    rustls_pemfile::certs(&mut reader)
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("parsing certs from {path}"))
+}
+
+/// Builds a client certificate verifier that trusts only certs chaining
+/// to `ca_file`, so the TLS handshake itself rejects unrecognized
+/// clients before any request reaches the application.
+///
+/// Under [`AuthMode::MutualTls`] a client cert is mandatory; under
+/// [`AuthMode::Both`] the handshake also accepts clients that present no
+/// certificate at all, so an API key alone remains sufficient per the
+/// request's "accept either" contract — app-level auth still rejects
+/// them if they bring neither.
+fn build_client_verifier(ca_file: &str, auth_mode: AuthMode) -> anyhow::Result<Arc<dyn ClientCertVerifier>> {
+    let mut roots = RootCertStore::empty();
+    for cert in load_certs(ca_file)? {
+        roots
+            .add(cert)
+            .context("adding TLS_CLIENT_CA_FILE cert to trust store")?;
+    }
+
+    let builder = WebPkiClientVerifier::builder(Arc::new(roots));
+    let builder = match auth_mode {
+        AuthMode::Both => builder.allow_unauthenticated(),
+        _ => builder,
+    };
+
+    builder.build().context("building mutual TLS client verifier")
+}
+
+/// Builds the rustls server config used for TLS termination.
+///
+/// Loads the PEM cert chain and private key named by
+/// [`TlsConfig::cert_file`] / [`TlsConfig::key_file`]. When `auth_mode`
+/// calls for mutual TLS, the client verifier built from
+/// `TLS_CLIENT_CA_FILE` is attached as well; otherwise the handshake
+/// accepts clients without a certificate.
+fn build_rustls_config(tls: &TlsConfig, auth: &AuthConfig) -> anyhow::Result<rustls::ServerConfig> {
+    let cert_path = tls
+        .cert_file
+        .as_ref()
+        .context("TLS enabled but TLS_CERT_FILE is not set")?;
+    let key_path = tls
+        .key_file
+        .as_ref()
+        .context("TLS enabled but TLS_KEY_FILE is not set")?;
+
+    let certs = load_certs(cert_path)?;
+
+    let mut key_reader = BufReader::new(
+        File::open(key_path).with_context(|| format!("opening TLS_KEY_FILE {key_path}"))?,
+    );
+    let key = rustls_pemfile::private_key(&mut key_reader)
+        .with_context(|| format!("parsing private key from {key_path}"))?
+        .with_context(|| format!("no private key found in {key_path}"))?;
+
+    let builder = rustls::ServerConfig::builder();
+    let builder = match (auth.auth_mode, auth.client_ca_file.as_deref()) {
+        (mode @ (AuthMode::MutualTls | AuthMode::Both), Some(ca_file)) => {
+            builder.with_client_cert_verifier(build_client_verifier(ca_file, mode)?)
+        }
+        _ => builder.with_no_client_auth(),
+    };
+
+    builder
+        .with_single_cert(certs, key)
+        .context("building rustls server config")
+}
+
+/// Extracts the subject common name from a DER-encoded certificate.
+fn common_name(cert: &CertificateDer<'_>) -> Option<String> {
+    let (_, parsed) = x509_parser::parse_x509_certificate(cert.as_ref()).ok()?;
+    let cn = parsed.subject().iter_common_name().next()?;
+    cn.as_str().ok().map(String::from)
+}
+
+/// `on_connect` callback that reads the client's verified certificate
+/// off the TLS stream and stores its common name as request-local
+/// [`ClientIdentity`] data, so handlers can authorize by client CN.
+fn client_identity_conn_data(connection: &dyn Any, data: &mut Extensions) {
+    let Some(tls_stream) = connection.downcast_ref::<TlsStream<TcpStream>>() else {
+        return;
+    };
+
+    let identity = tls_stream
+        .get_ref()
+        .1
+        .peer_certificates()
+        .and_then(|certs| certs.first())
+        .and_then(common_name)
+        .map(|common_name| ClientIdentity {
+            common_name: Some(common_name),
+        })
+        .unwrap_or_default();
+
+    data.insert(identity);
+}
+
+async fn index(server_name: web::Data<String>) -> HttpResponse {
+    HttpResponse::Ok().body(format!("mcp-server-as-http-node: {}", server_name.get_ref()))
+}
+
+/// Spawns a task that re-reads `path` into `keys` on every `SIGHUP`, so
+/// `HTTP_API_KEY_FILE` can be rotated without restarting the process.
+fn spawn_api_key_reload(keys: ApiKeyStore, path: String) {
+    tokio::spawn(async move {
+        let Ok(mut sighup) = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())
+        else {
+            eprintln!("[WARN] failed to install SIGHUP handler for HTTP_API_KEY_FILE reload");
+            return;
+        };
+
+        while sighup.recv().await.is_some() {
+            match keys.reload() {
+                Ok(()) => println!("[INFO] reloaded HTTP_API_KEY_FILE {path}"),
+                Err(err) => eprintln!("[WARN] failed to reload HTTP_API_KEY_FILE {path}: {err:#}"),
+            }
+        }
+    });
+}
+
+/// The runtime backend this server resolved at startup: either the
+/// built-in Node.js plugin, or one discovered by name in a
+/// [`PluginRegistry`] loaded from `PLUGIN_DIR`.
+enum ResolvedRuntime {
+    Node(NodeRuntimePlugin),
+    Plugin {
+        registry: PluginRegistry,
+        name: String,
+    },
+}
+
+impl ResolvedRuntime {
+    fn resolve(config: &ServerConfig) -> anyhow::Result<Self> {
+        match &config.runtime_type {
+            Runtime::Node => Ok(Self::Node(NodeRuntimePlugin)),
+            Runtime::Plugin(name) => {
+                let dir = config
+                    .plugin_dir
+                    .as_deref()
+                    .context("RUNTIME_PLUGIN is set but PLUGIN_DIR is not configured")?;
+                let registry = PluginRegistry::load_dir(dir)
+                    .with_context(|| format!("loading runtime plugins from {dir}"))?;
+                if registry.get(name).is_none() {
+                    anyhow::bail!("runtime plugin '{name}' was not found under PLUGIN_DIR {dir}");
+                }
+                Ok(Self::Plugin {
+                    registry,
+                    name: name.clone(),
+                })
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Self::Node(plugin) => plugin.name(),
+            Self::Plugin { name, .. } => name,
+        }
+    }
+
+    fn spawn(&self, cfg: &crate::config::RuntimeConfig) -> anyhow::Result<std::process::Child> {
+        match self {
+            Self::Node(plugin) => plugin.spawn(cfg),
+            Self::Plugin { registry, name } => registry
+                .get(name)
+                .context("runtime plugin disappeared after startup resolution")?
+                .spawn(cfg),
+        }
+    }
+}
+
+/// The running MCP HTTP server.
+pub struct McpHttpServer {
+    config: ServerConfig,
+    runtime: ResolvedRuntime,
+}
+
+impl McpHttpServer {
+    pub async fn new(config: ServerConfig) -> anyhow::Result<Self> {
+        let runtime = ResolvedRuntime::resolve(&config)?;
+        Ok(Self { config, runtime })
+    }
+
+    /// Binds and runs the server, choosing plain HTTP or TLS per
+    /// [`TlsConfig::enable_tls`]. Falls back to plain HTTP when
+    /// `enable_tls` is set but `cert_file`/`key_file` aren't, so existing
+    /// deployments that don't configure the new TLS env vars keep
+    /// working unchanged.
+    ///
+    /// That fallback would silently strand `AuthMode::MutualTls`/`Both`,
+    /// which depend on TLS actually being active to ever populate a
+    /// [`ClientIdentity`] — so unlike the TLS fallback itself, that
+    /// combination fails fast at startup rather than leaving the
+    /// client-certificate path unusable with no diagnostic (under
+    /// `MutualTls`, every request would 401).
+    pub async fn start(&mut self) -> anyhow::Result<()> {
+        if self.config.transport == Transport::WebSocket {
+            anyhow::bail!(
+                "TRANSPORT=ws is not implemented yet (no WebSocket route is registered); \
+                 set TRANSPORT to \"http\" or \"sse\" instead"
+            );
+        }
+
+        let tls_configured = self.config.tls.enable_tls
+            && self.config.tls.cert_file.is_some()
+            && self.config.tls.key_file.is_some();
+        let mtls_required = matches!(self.config.auth.auth_mode, AuthMode::MutualTls | AuthMode::Both);
+        if mtls_required && !tls_configured {
+            anyhow::bail!(
+                "auth_mode requires mutual TLS (TLS_CLIENT_CA_FILE is set) but TLS is not \
+                 active (TLS_CERT_FILE/TLS_KEY_FILE are not both set, or TLS is disabled); \
+                 configure TLS or unset TLS_CLIENT_CA_FILE, otherwise no client certificate \
+                 could ever be verified"
+            );
+        }
+
+        let mut child = self
+            .runtime
+            .spawn(&self.config.runtime_config)
+            .with_context(|| format!("spawning '{}' runtime process", self.runtime.name()))?;
+        println!(
+            "[INFO] Spawned '{}' runtime process (pid {})",
+            self.runtime.name(),
+            child.id()
+        );
+
+        let sse_state = if self.config.transport == Transport::Sse {
+            Some(SseState::spawn(&mut child).context("wiring HTTP+SSE transport to runtime stdio")?)
+        } else {
+            None
+        };
+
+        let health_state = HealthState {
+            health: RuntimeHealth::spawn(child),
+            server_name: self.config.server_name.clone(),
+            runtime_type: self.runtime.name().to_string(),
+        };
+
+        let request_auth = RequestAuth {
+            config: self.config.auth.clone(),
+            keys: ApiKeyStore::load(&self.config.auth).context("loading HTTP_API_KEY_FILE")?,
+        };
+        if let Some(path) = self.config.auth.api_key_file.clone() {
+            spawn_api_key_reload(request_auth.keys.clone(), path);
+        }
+
+        let addr = (self.config.host.clone(), self.config.port);
+        let server_name = self.config.server_name.clone();
+        let enable_compression = self.config.enable_compression;
+
+        let http_server = HttpServer::new(move || {
+            let mut routes = web::scope("")
+                .app_data(web::Data::new(request_auth.clone()))
+                .wrap(from_fn(require_auth))
+                .route("/", web::get().to(index));
+
+            if let Some(state) = sse_state.clone() {
+                routes = routes
+                    .app_data(web::Data::new(state))
+                    .route("/sse", web::get().to(sse::sse))
+                    .route("/messages", web::post().to(sse::messages));
+            }
+
+            App::new()
+                .wrap(Condition::new(enable_compression, Compress::default()))
+                .app_data(web::Data::new(server_name.clone()))
+                .app_data(web::Data::new(health_state.clone()))
+                .route("/healthz", web::get().to(health::healthz))
+                .service(routes)
+        });
+
+        let bind_desc = format!("{}:{}", addr.0, addr.1);
+
+        if tls_configured {
+            let tls_config = build_rustls_config(&self.config.tls, &self.config.auth)?;
+            http_server
+                .on_connect(client_identity_conn_data)
+                .bind_rustls_0_23(addr, tls_config)
+                .with_context(|| format!("binding TLS listener on {bind_desc}"))?
+                .run()
+                .await?;
+        } else {
+            http_server
+                .bind(addr)
+                .with_context(|| format!("binding listener on {bind_desc}"))?
+                .run()
+                .await?;
+        }
+
+        Ok(())
+    }
+}
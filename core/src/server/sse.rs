@@ -0,0 +1,296 @@
+//! MCP's HTTP+SSE transport: a `GET /sse` endpoint streaming JSON-RPC
+//! responses and notifications from the runtime process's stdout, and a
+//! `POST /messages` endpoint forwarding client requests to its stdin.
+//!
+//! All sessions share one runtime process's stdio, so a response must be
+//! routed back to the one session that asked for it rather than fanned
+//! out to everyone: `messages()` records which session is awaiting the
+//! JSON-RPC `id` in a request before writing it to stdin, and the
+//! stdout-reading thread looks that id up to pick the right session's
+//! channel. Lines with no `id` (JSON-RPC notifications), and lines whose
+//! `id` matches no pending request (e.g. one whose session has since
+//! disconnected), aren't claimed by any connected session, so they're
+//! broadcast to all of them.
+//!
+//! The runtime process is bridged onto async channels by two blocking
+//! threads (one per stdio handle), since [`RuntimePlugin::spawn`]
+//! returns a plain [`std::process::Child`] rather than a tokio one.
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::process::Child;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use actix_web::http::header;
+use actix_web::web::{Bytes, Data};
+use actix_web::{web, HttpResponse, Responder};
+use anyhow::Context;
+use futures_util::{stream, StreamExt};
+use tokio::sync::mpsc;
+
+/// Extracts a JSON-RPC message's `id` field as a map key, or `None` for
+/// notifications (no `id`) and unparseable lines.
+fn message_id(line: &str) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_str(line).ok()?;
+    match value.get("id") {
+        Some(id) if !id.is_null() => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+/// Bridges a spawned runtime process's stdio to the HTTP+SSE transport.
+///
+/// Every `GET /sse` connection gets its own session id and its own
+/// outbound channel, registered in `sessions`; `POST
+/// /messages?sessionId=...` forwards its body to the process's shared
+/// stdin after checking the session id names a connection that is still
+/// open, and records the request's `id` in `pending` so the stdout
+/// reader can route the eventual response back to just that session.
+#[derive(Clone)]
+pub struct SseState {
+    stdin_tx: mpsc::UnboundedSender<String>,
+    sessions: Arc<Mutex<HashMap<String, mpsc::UnboundedSender<String>>>>,
+    pending: Arc<Mutex<HashMap<String, String>>>,
+    next_session_id: Arc<AtomicU64>,
+}
+
+impl SseState {
+    /// Takes ownership of `child`'s stdio and spawns the bridging
+    /// threads. Must be called once per spawned runtime process.
+    pub fn spawn(child: &mut Child) -> anyhow::Result<Self> {
+        let stdout = child
+            .stdout
+            .take()
+            .context("runtime process has no captured stdout")?;
+        let stdin = child
+            .stdin
+            .take()
+            .context("runtime process has no captured stdin")?;
+
+        let sessions: Arc<Mutex<HashMap<String, mpsc::UnboundedSender<String>>>> =
+            Arc::new(Mutex::new(HashMap::new()));
+        let pending: Arc<Mutex<HashMap<String, String>>> = Arc::new(Mutex::new(HashMap::new()));
+
+        let reader_sessions = Arc::clone(&sessions);
+        let reader_pending = Arc::clone(&pending);
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                let owner = message_id(&line)
+                    .and_then(|id| reader_pending.lock().expect("pending map poisoned").remove(&id));
+
+                let sessions = reader_sessions.lock().expect("session map poisoned");
+                match owner {
+                    // A response: only the session that sent the matching
+                    // request gets it.
+                    Some(session_id) => {
+                        if let Some(tx) = sessions.get(&session_id) {
+                            let _ = tx.send(line);
+                        }
+                    }
+                    // A notification, or a response whose session already
+                    // disconnected: not solicited by any one session, so
+                    // every currently-connected one gets it.
+                    None => {
+                        for tx in sessions.values() {
+                            let _ = tx.send(line.clone());
+                        }
+                    }
+                }
+            }
+        });
+
+        let (stdin_tx, mut stdin_rx) = mpsc::unbounded_channel::<String>();
+        std::thread::spawn(move || {
+            let mut stdin = stdin;
+            while let Some(message) = stdin_rx.blocking_recv() {
+                if writeln!(stdin, "{message}").is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self {
+            stdin_tx,
+            sessions,
+            pending,
+            next_session_id: Arc::new(AtomicU64::new(1)),
+        })
+    }
+
+    fn open_session(&self) -> (String, mpsc::UnboundedReceiver<String>) {
+        let id = self.next_session_id.fetch_add(1, Ordering::Relaxed);
+        let session_id = format!("session-{id}");
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.sessions
+            .lock()
+            .expect("session map poisoned")
+            .insert(session_id.clone(), tx);
+        (session_id, rx)
+    }
+
+    /// Drops `session_id`'s outbound channel and any requests it's still
+    /// awaiting a response for, so a client that disconnects mid-request
+    /// doesn't pin that request's `pending` entry forever.
+    fn close_session(&self, session_id: &str) {
+        self.sessions
+            .lock()
+            .expect("session map poisoned")
+            .remove(session_id);
+        self.pending
+            .lock()
+            .expect("pending map poisoned")
+            .retain(|_, owner| owner != session_id);
+    }
+
+    fn has_session(&self, session_id: &str) -> bool {
+        self.sessions
+            .lock()
+            .expect("session map poisoned")
+            .contains_key(session_id)
+    }
+
+    /// Records that `session_id` is awaiting the response to `message`'s
+    /// JSON-RPC `id`, if it has one, so the stdout reader can route the
+    /// matching response back to just this session.
+    fn register_pending(&self, session_id: &str, message: &str) {
+        if let Some(id) = message_id(message) {
+            self.pending
+                .lock()
+                .expect("pending map poisoned")
+                .insert(id, session_id.to_string());
+        }
+    }
+}
+
+/// Removes its session from [`SseState`] when dropped, which happens
+/// whenever the `GET /sse` response stream stops being polled — on a
+/// clean end-of-stream, a client disconnect, or the connection erroring
+/// out. This is the only cleanup path: sessions are opened far more
+/// often than the runtime process exits, so relying on the stdout
+/// channel closing alone would leak an entry per connection for the life
+/// of the process.
+struct SessionGuard {
+    state: Arc<SseState>,
+    session_id: String,
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        self.state.close_session(&self.session_id);
+    }
+}
+
+/// `GET /sse`: opens an `event-stream` response. The first frame tells
+/// the client where to `POST` follow-up messages, per the MCP HTTP+SSE
+/// transport; subsequent frames are JSON-RPC messages routed to this
+/// session by [`SseState`].
+pub async fn sse(state: Data<SseState>) -> impl Responder {
+    let state = state.into_inner();
+    let (session_id, rx) = state.open_session();
+
+    let endpoint_frame = format!("event: endpoint\ndata: /messages?sessionId={session_id}\n\n");
+    let first = stream::once(async move { Ok::<_, actix_web::Error>(Bytes::from(endpoint_frame)) });
+
+    let guard = SessionGuard {
+        state: Arc::clone(&state),
+        session_id,
+    };
+    let rest = stream::unfold((rx, guard), |(mut rx, guard)| async move {
+        rx.recv().await.map(|line| {
+            let frame = Bytes::from(format!("data: {line}\n\n"));
+            (Ok::<_, actix_web::Error>(frame), (rx, guard))
+        })
+    });
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(first.chain(rest))
+}
+
+/// `POST /messages?sessionId=...`: forwards the request body to the
+/// runtime process's stdin, after checking `sessionId` names a session
+/// opened by a still-connected `GET /sse` client.
+pub async fn messages(
+    state: Data<SseState>,
+    query: web::Query<MessagesQuery>,
+    body: Bytes,
+) -> HttpResponse {
+    if !state.has_session(&query.session_id) {
+        return HttpResponse::NotFound().body("unknown sessionId");
+    }
+
+    let message = match std::str::from_utf8(&body) {
+        Ok(message) => message.to_string(),
+        Err(_) => return HttpResponse::BadRequest().body("message body must be UTF-8"),
+    };
+
+    state.register_pending(&query.session_id, &message);
+
+    match state.stdin_tx.send(message) {
+        Ok(()) => HttpResponse::Accepted().finish(),
+        Err(_) => HttpResponse::ServiceUnavailable().body("runtime process stdin closed"),
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct MessagesQuery {
+    #[serde(rename = "sessionId")]
+    session_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_state() -> SseState {
+        let (stdin_tx, _rx) = mpsc::unbounded_channel();
+        SseState {
+            stdin_tx,
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+            pending: Arc::new(Mutex::new(HashMap::new())),
+            next_session_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+
+    #[test]
+    fn opened_session_is_visible_to_has_session() {
+        let state = test_state();
+        let (session_id, _rx) = state.open_session();
+        assert!(state.has_session(&session_id));
+    }
+
+    #[test]
+    fn unknown_session_is_absent() {
+        let state = test_state();
+        assert!(!state.has_session("no-such-session"));
+    }
+
+    #[test]
+    fn closed_session_is_no_longer_present() {
+        let state = test_state();
+        let (session_id, _rx) = state.open_session();
+        state.close_session(&session_id);
+        assert!(!state.has_session(&session_id));
+    }
+
+    #[test]
+    fn closing_a_session_drops_its_pending_requests() {
+        let state = test_state();
+        let (session_id, _rx) = state.open_session();
+        state.register_pending(&session_id, r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#);
+        assert_eq!(state.pending.lock().unwrap().len(), 1);
+
+        state.close_session(&session_id);
+        assert!(state.pending.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn notifications_without_an_id_are_not_tracked_as_pending() {
+        let state = test_state();
+        let (session_id, _rx) = state.open_session();
+        state.register_pending(&session_id, r#"{"jsonrpc":"2.0","method":"notifications/ping"}"#);
+        assert!(state.pending.lock().unwrap().is_empty());
+    }
+}
@@ -1,15 +1,21 @@
 use mcp_server_as_http_core::{
     server::McpHttpServer,
-    config::{ServerConfig, RuntimeConfig, NodeConfig},
-    auth::AuthConfig,
+    config::{ServerConfig, RuntimeConfig, NodeConfig, TlsConfig, Transport},
+    auth::{AuthConfig, AuthMode},
     runtime::Runtime,
 };
+use std::collections::HashSet;
 use std::env;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("[INFO] Starting MCP HTTP Server for Node.js runtime...");
 
+    let plugin_dir = env::var("PLUGIN_DIR").ok();
+    if let Some(plugin_dir) = &plugin_dir {
+        println!("[INFO] Runtime plugin directory: {}", plugin_dir);
+    }
+
     // Environment-based configuration
     let config_file = env::var("MCP_CONFIG_FILE")
         .unwrap_or_else(|_| "mcp_servers.config.json".to_string());
@@ -20,8 +26,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .parse::<u16>()?;
 
     // Authentication configuration
+    // HTTP_API_KEY accepts a comma-separated list so multiple keys can be
+    // valid at once (e.g. while rotating to a new one).
+    let api_keys: HashSet<String> = env::var("HTTP_API_KEY")
+        .map(|raw| {
+            raw.split(',')
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default();
+    let api_key_file = env::var("HTTP_API_KEY_FILE").ok();
+    let client_ca_file = env::var("TLS_CLIENT_CA_FILE").ok();
+    let auth_mode = match (!api_keys.is_empty() || api_key_file.is_some(), client_ca_file.is_some()) {
+        (true, true) => AuthMode::Both,
+        (false, true) => AuthMode::MutualTls,
+        _ => AuthMode::ApiKey,
+    };
     let auth_config = AuthConfig {
-        api_key: env::var("HTTP_API_KEY").ok(),
+        api_keys,
+        api_key_file,
+        client_ca_file,
+        auth_mode,
         enabled: env::var("DISABLE_AUTH")
             .unwrap_or_else(|_| "false".to_string())
             .parse::<bool>()
@@ -29,6 +56,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .unwrap_or(true),
     };
 
+    // TLS configuration
+    let tls_config = TlsConfig {
+        cert_file: env::var("TLS_CERT_FILE").ok(),
+        key_file: env::var("TLS_KEY_FILE").ok(),
+        enable_tls: env::var("DISABLE_TLS")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse::<bool>()
+            .map(|disable| !disable)
+            .unwrap_or(true),
+    };
+
+    // Runtime selection: a built-in Node runtime, or a dynamically loaded
+    // plugin discovered by name under PLUGIN_DIR at startup.
+    let runtime_type = match env::var("RUNTIME_PLUGIN") {
+        Ok(plugin_name) => Runtime::Plugin(plugin_name),
+        Err(_) => Runtime::Node,
+    };
+
+    // MCP transport selection: standard HTTP, WebSocket, or HTTP+SSE
+    let transport = match env::var("TRANSPORT")
+        .unwrap_or_else(|_| "http".to_string())
+        .as_str()
+    {
+        "ws" => Transport::WebSocket,
+        "sse" => Transport::Sse,
+        _ => Transport::Http,
+    };
+
     // Node.js optimized runtime configuration
     let node_config = NodeConfig {
         version: ">=18.0.0".to_string(),
@@ -48,13 +103,20 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let server_config = ServerConfig {
         config_file,
         server_name,
-        runtime_type: Runtime::Node,
+        runtime_type,
         runtime_config: RuntimeConfig::Node(node_config),
         port,
         host: env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
         auth: auth_config,
+        tls: tls_config,
+        transport,
+        enable_compression: env::var("ENABLE_COMPRESSION")
+            .unwrap_or_else(|_| "true".to_string())
+            .parse()
+            .unwrap_or(true),
         work_directory: env::var("WORK_DIR")
             .unwrap_or_else(|_| "/tmp/mcp-servers".to_string()),
+        plugin_dir,
     };
 
     // Start the MCP HTTP server